@@ -2,8 +2,261 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use tauri::{Manager, Emitter};
+use serde::Serialize;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+/// Monotonic counter keeping per-story window labels unique within a session.
+static WINDOW_SEQ: AtomicUsize = AtomicUsize::new(0);
+
+/// Which search location satisfied a resolution — carried alongside the resolved path purely
+/// for diagnostics (logging, error messages).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResolvedFrom {
+    ResourceDir,
+    CurrentDir,
+    ExeDir,
+    DevLocation,
+}
+
+impl fmt::Display for ResolvedFrom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            ResolvedFrom::ResourceDir => "bundled resources",
+            ResolvedFrom::CurrentDir => "current directory",
+            ResolvedFrom::ExeDir => "executable directory",
+            ResolvedFrom::DevLocation => "dev location",
+        };
+        f.write_str(label)
+    }
+}
+
+/// A successful resolution: the concrete file on disk and where it was found.
+struct Resolved {
+    path: PathBuf,
+    from: ResolvedFrom,
+}
+
+/// Single source of truth for locating a bundled resource.
+///
+/// Resolves a logical key (e.g. `"welcome"`, `"shaders/foo.js"`) against an ordered search-path
+/// list — bundled resources, current dir, exe dir, then the dev tree — returning the first match
+/// together with which location it came from. An optional resource map (loaded from a
+/// `resources.json` alongside the resources) aliases logical names to concrete relative paths, so
+/// new bundled resource types become a one-line map entry.
+struct ResourceResolver {
+    roots: Vec<(ResolvedFrom, PathBuf)>,
+    map: HashMap<String, String>,
+}
+
+impl ResourceResolver {
+    /// Builds a resolver from the running app, probing the same locations the individual commands
+    /// used to walk by hand.
+    fn from_app(app: &tauri::AppHandle) -> Self {
+        let mut roots = Vec::new();
+        if let Ok(p) = app.path().resource_dir() {
+            roots.push((ResolvedFrom::ResourceDir, p));
+        }
+        if let Ok(p) = std::env::current_dir() {
+            roots.push((ResolvedFrom::CurrentDir, p));
+        }
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(dir) = exe.parent() {
+                roots.push((ResolvedFrom::ExeDir, dir.to_path_buf()));
+            }
+        }
+        if let Ok(resource) = app.path().resource_dir() {
+            if let Some(dev) = resource
+                .parent()
+                .and_then(|p| p.parent())
+                .map(|p| p.join("dist-tstauri"))
+            {
+                roots.push((ResolvedFrom::DevLocation, dev));
+            }
+        }
+
+        // Start from the built-in aliases, then let a `resources.json` override/extend them.
+        let mut map = default_resource_map();
+        for (_, root) in &roots {
+            if let Ok(text) = fs::read_to_string(root.join("resources.json")) {
+                if let Ok(extra) = serde_json::from_str::<HashMap<String, String>>(&text) {
+                    map.extend(extra);
+                    break;
+                }
+            }
+        }
+
+        Self { roots, map }
+    }
+
+    /// Resolves `key` to a concrete file, following the resource map alias if one exists and
+    /// otherwise treating the key as a relative path. Returns the first existing candidate.
+    fn resolve(&self, key: &str) -> Option<Resolved> {
+        let rel = self.map.get(key).map(String::as_str).unwrap_or(key);
+        for (from, root) in &self.roots {
+            let candidate = root.join(rel);
+            if candidate.exists() {
+                return Some(Resolved {
+                    path: candidate,
+                    from: *from,
+                });
+            }
+        }
+        None
+    }
+}
+
+/// Built-in logical-name aliases, extended at runtime by `resources.json` when present.
+fn default_resource_map() -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    map.insert("welcome".to_string(), "index.md".to_string());
+    map
+}
+
+/// Scope controlling which on-disk locations the `tstorie://` protocol is permitted to serve.
+///
+/// Mirrors the asset-protocol scope model: a request is only fulfilled if the resolved file
+/// lives under one of the allowed roots. Callers add the resource dir, exe dir, and any
+/// explicitly-permitted story folders at startup.
+#[derive(Default)]
+struct AssetScope {
+    allowed: Vec<PathBuf>,
+}
+
+impl AssetScope {
+    /// Permit serving files rooted at `dir`. Stored canonicalized so prefix checks are robust
+    /// against symlinks and `.`/`..` components; silently ignored if the path can't be resolved.
+    fn allow(&mut self, dir: PathBuf) {
+        if let Ok(canon) = dir.canonicalize() {
+            if !self.allowed.contains(&canon) {
+                self.allowed.push(canon);
+            }
+        }
+    }
+
+    /// True when `path` resolves to a real file under one of the allowed roots.
+    fn is_allowed(&self, path: &Path) -> bool {
+        let Ok(canon) = path.canonicalize() else {
+            return false;
+        };
+        self.allowed.iter().any(|root| canon.starts_with(root))
+    }
+
+    /// The allowlisted roots, used both as the candidate bases the protocol searches and as the
+    /// boundaries `is_allowed` enforces — so the protocol can never look outside the scope.
+    fn roots(&self) -> Vec<PathBuf> {
+        self.allowed.clone()
+    }
+}
+
+/// Managed wrapper so the protocol handler and `setup()` can share one mutable scope.
+struct ScopeState(Mutex<AssetScope>);
+
+/// Maps a `tstorie://<category>/...` host to the subdirectory its files live under, or `None`
+/// for an unknown category.
+fn category_subdir(category: &str) -> Option<&'static str> {
+    match category {
+        "wasm" => Some(""),
+        "shader" => Some("shaders"),
+        "asset" => Some(""),
+        _ => None,
+    }
+}
+
+/// Best-effort content type from a file extension, defaulting to an opaque binary blob.
+fn mime_for(path: &Path) -> &'static str {
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("wasm") => "application/wasm",
+        Some("js") | Some("mjs") => "text/javascript",
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("svg") => "image/svg+xml",
+        Some("md") => "text/markdown",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Streams a bundled asset referenced as `tstorie://<category>/<relative/path>` straight to the
+/// webview, bypassing the base64 IPC round-trip used by the `get_bundled_*` commands.
+///
+/// Rejects path-traversal attempts and anything that resolves outside the configured
+/// [`AssetScope`]; unknown categories and missing files return 404.
+fn serve_asset(
+    app: &tauri::AppHandle,
+    request: tauri::http::Request<Vec<u8>>,
+) -> tauri::http::Response<Cow<'static, [u8]>> {
+    let empty = |code: u16| {
+        tauri::http::Response::builder()
+            .status(code)
+            .body(Cow::Owned(Vec::new()))
+            .unwrap()
+    };
+
+    let uri = request.uri();
+    let category = uri.host().unwrap_or("");
+    let rel = uri.path().trim_start_matches('/');
+
+    // Refuse empty or traversal-containing references before touching the filesystem.
+    if rel.is_empty() || rel.split('/').any(|seg| seg == ".." || seg.is_empty()) {
+        return empty(403);
+    }
+
+    let Some(subdir) = category_subdir(category) else {
+        return empty(404);
+    };
+
+    // Candidate bases come from the allowlist itself, so the protocol can only ever reach inside
+    // the configured scope.
+    let roots = {
+        let scope = app.state::<ScopeState>();
+        let guard = scope.0.lock().unwrap();
+        guard.roots()
+    };
+
+    for base in roots {
+        let mut candidate = base;
+        if !subdir.is_empty() {
+            candidate.push(subdir);
+        }
+        candidate.push(rel);
+        if !candidate.exists() {
+            continue;
+        }
+
+        // Defence in depth against symlinks escaping the root.
+        let allowed = {
+            let scope = app.state::<ScopeState>();
+            let guard = scope.0.lock().unwrap();
+            guard.is_allowed(&candidate)
+        };
+        if !allowed {
+            continue;
+        }
+
+        return match fs::read(&candidate) {
+            Ok(bytes) => tauri::http::Response::builder()
+                .status(200)
+                .header("Content-Type", mime_for(&candidate))
+                .header("Access-Control-Allow-Origin", "*")
+                .body(Cow::Owned(bytes))
+                .unwrap(),
+            Err(_) => empty(500),
+        };
+    }
+
+    empty(404)
+}
 
 #[tauri::command]
 fn load_markdown_content(path: String) -> Result<String, String> {
@@ -11,29 +264,209 @@ fn load_markdown_content(path: String) -> Result<String, String> {
         .map_err(|e| format!("Failed to read file: {}", e))
 }
 
-#[tauri::command]
-fn get_bundled_wasm_file(app: tauri::AppHandle, filename: String) -> Result<Vec<u8>, String> {
-    // Try bundled resources first
-    if let Ok(resource_path) = app.path().resource_dir() {
-        let file_path = resource_path.join(&filename);
-        if file_path.exists() {
-            return fs::read(&file_path)
-                .map_err(|e| format!("Failed to read {}: {}", filename, e));
+/// A single log record forwarded to the webview as a `log-record` event so an in-app console can
+/// display backend diagnostics on GUI builds where stderr is discarded.
+#[derive(Clone, Serialize)]
+struct LogRecord {
+    level: String,
+    target: String,
+    message: String,
+}
+
+/// Minimal size-based rotating log writer. When the active file would exceed `max_bytes` it is
+/// renamed to `<stem>.log.1` (replacing any previous backup) and a fresh file is started, keeping
+/// the log in the app data dir bounded without pulling in a rotation crate.
+struct RotatingFile {
+    path: PathBuf,
+    handle: fs::File,
+    written: u64,
+    max_bytes: u64,
+}
+
+impl RotatingFile {
+    fn open(path: PathBuf, max_bytes: u64) -> std::io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
         }
+        let handle = fs::OpenOptions::new().create(true).append(true).open(&path)?;
+        let written = handle.metadata().map(|m| m.len()).unwrap_or(0);
+        Ok(Self { path, handle, written, max_bytes })
     }
-    
-    // Try current directory (portable builds)
-    let cwd_path = std::env::current_dir()
-        .ok()
-        .map(|p| p.join(&filename));
-    if let Some(file_path) = cwd_path {
-        if file_path.exists() {
-            return fs::read(&file_path)
-                .map_err(|e| format!("Failed to read {}: {}", filename, e));
+
+    fn write_line(&mut self, line: &str) -> std::io::Result<()> {
+        let len = line.len() as u64 + 1;
+        if self.written + len > self.max_bytes {
+            self.rotate()?;
         }
+        self.handle.write_all(line.as_bytes())?;
+        self.handle.write_all(b"\n")?;
+        self.written += len;
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> std::io::Result<()> {
+        self.handle.flush()?;
+        let _ = fs::rename(&self.path, self.path.with_extension("log.1"));
+        self.handle = fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&self.path)?;
+        self.written = 0;
+        Ok(())
     }
-    
-    Err(format!("{} not found in resources or current directory", filename))
+}
+
+/// `log` facade backend that fans each record out to both the rotating file and the webview.
+struct FrontendLogger {
+    app: tauri::AppHandle,
+    file: Mutex<RotatingFile>,
+}
+
+impl log::Log for FrontendLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::Level::Debug
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let message = record.args().to_string();
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.write_line(&format!(
+                "[{}] {}: {}",
+                record.level(),
+                record.target(),
+                message
+            ));
+        }
+        let _ = self.app.emit(
+            "log-record",
+            LogRecord {
+                level: record.level().to_string(),
+                target: record.target().to_string(),
+                message,
+            },
+        );
+    }
+
+    fn flush(&self) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.handle.flush();
+        }
+    }
+}
+
+/// Installs the [`FrontendLogger`] as the global `log` sink. Writes to `logs/tstorie.log` under the
+/// app data dir; best-effort — a logger-init failure must not keep the app from starting.
+fn init_logger(app: &tauri::AppHandle) {
+    let log_path = match app.path().app_data_dir() {
+        Ok(dir) => dir.join("logs").join("tstorie.log"),
+        Err(_) => return,
+    };
+    let Ok(file) = RotatingFile::open(log_path, 1024 * 1024) else {
+        return;
+    };
+    let logger = FrontendLogger {
+        app: app.clone(),
+        file: Mutex::new(file),
+    };
+    if log::set_boxed_logger(Box::new(logger)).is_ok() {
+        log::set_max_level(log::LevelFilter::Debug);
+    }
+}
+
+/// A single entry in a directory listing, shaped for a file-browser-style story index.
+#[derive(Serialize)]
+struct StoryEntry {
+    name: String,
+    path: String,
+    size: u64,
+    is_directory: bool,
+    is_file: bool,
+    /// Last-modified time as unix epoch milliseconds, when the platform reports it.
+    modified: Option<u64>,
+    /// Creation time as unix epoch milliseconds, when the platform reports it.
+    created: Option<u64>,
+    /// Number of immediate children, populated only for directories.
+    child_count: Option<usize>,
+}
+
+/// Converts a [`std::time::SystemTime`] to unix epoch milliseconds, discarding pre-epoch times.
+fn epoch_millis(time: std::io::Result<std::time::SystemTime>) -> Option<u64> {
+    time.ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64)
+}
+
+/// True when `name` ends with one of the supplied (lowercase, dot-less) extensions.
+fn matches_extension(name: &str, extensions: &[String]) -> bool {
+    Path::new(name)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|ext| {
+            let ext = ext.to_ascii_lowercase();
+            extensions.iter().any(|want| want.eq_ignore_ascii_case(&ext))
+        })
+        .unwrap_or(false)
+}
+
+/// Lists the contents of `path` as a browsable story library.
+///
+/// Files are filtered to the given `extensions` (dot-less, case-insensitive), defaulting to
+/// `md`/`png` covers; directories are always included so the index stays navigable. Entries that
+/// can't be read (permissions, races) are skipped rather than failing the whole listing.
+#[tauri::command]
+fn list_directory(path: String, extensions: Option<Vec<String>>) -> Result<Vec<StoryEntry>, String> {
+    let extensions = extensions.unwrap_or_else(|| vec!["md".to_string(), "png".to_string()]);
+
+    let read_dir = fs::read_dir(&path)
+        .map_err(|e| format!("Failed to read directory '{}': {}", path, e))?;
+
+    let mut entries = Vec::new();
+    for entry in read_dir {
+        // Skip entries we can't even enumerate rather than aborting the listing.
+        let Ok(entry) = entry else { continue };
+        let Ok(metadata) = entry.metadata() else { continue };
+
+        let is_directory = metadata.is_dir();
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        // Surface every directory, but only files whose extension is requested.
+        if !is_directory && !matches_extension(&name, &extensions) {
+            continue;
+        }
+
+        let entry_path = entry.path();
+        let child_count = if is_directory {
+            fs::read_dir(&entry_path).map(|it| it.count()).ok()
+        } else {
+            None
+        };
+
+        entries.push(StoryEntry {
+            name,
+            path: entry_path.to_string_lossy().to_string(),
+            size: metadata.len(),
+            is_directory,
+            is_file: metadata.is_file(),
+            modified: epoch_millis(metadata.modified()),
+            created: epoch_millis(metadata.created()),
+            child_count,
+        });
+    }
+
+    Ok(entries)
+}
+
+#[tauri::command]
+fn get_bundled_wasm_file(app: tauri::AppHandle, filename: String) -> Result<Vec<u8>, String> {
+    let resolved = ResourceResolver::from_app(&app).resolve(&filename).ok_or_else(|| {
+        log::error!("WASM file '{}' not found in any known location", filename);
+        format!("{} not found in resources or current directory", filename)
+    })?;
+    fs::read(&resolved.path).map_err(|e| format!("Failed to read {}: {}", filename, e))
 }
 
 #[tauri::command]
@@ -55,112 +488,219 @@ fn get_bundled_wasm_path(app: tauri::AppHandle) -> Result<String, String> {
 
 #[tauri::command]
 fn load_bundled_welcome(app: tauri::AppHandle) -> Result<String, String> {
-    // Try multiple locations in order of preference
-    
-    // 1. Try bundled resources directory (release builds with proper installers)
-    if let Ok(resource_path) = app.path().resource_dir() {
-        let file_path = resource_path.join("index.md");
-        eprintln!("Checking bundled resources: {:?}", file_path);
-        if file_path.exists() {
-            eprintln!("✓ Found in bundled resources");
-            return fs::read_to_string(&file_path)
-                .map_err(|e| format!("Failed to read welcome screen: {}", e));
-        } else {
-            eprintln!("  Not found in bundled resources");
+    match ResourceResolver::from_app(&app).resolve("welcome") {
+        Some(resolved) => {
+            log::debug!("Found welcome screen in {}: {:?}", resolved.from, resolved.path);
+            fs::read_to_string(&resolved.path)
+                .map_err(|e| format!("Failed to read welcome screen: {}", e))
         }
-    }
-    
-    // 2. Try executable directory (portable builds - most common)
-    if let Ok(exe_path) = std::env::current_exe() {
-        if let Some(exe_dir) = exe_path.parent() {
-            let file_path = exe_dir.join("index.md");
-            eprintln!("Checking executable directory: {:?}", file_path);
-            if file_path.exists() {
-                eprintln!("✓ Found in executable directory");
-                return fs::read_to_string(&file_path)
-                    .map_err(|e| format!("Failed to read welcome screen: {}", e));
-            } else {
-                eprintln!("  Not found in executable directory");
-            }
+        None => {
+            log::error!("Welcome screen (index.md) not found in any known location");
+            Err("Welcome screen (index.md) not found. Please ensure it's bundled in the app or in the same folder as the executable.".to_string())
         }
     }
-    
-    // 3. Try current working directory
-    if let Ok(cwd) = std::env::current_dir() {
-        let file_path = cwd.join("index.md");
-        eprintln!("Checking current directory: {:?}", file_path);
-        if file_path.exists() {
-            eprintln!("✓ Found in current directory");
-            return fs::read_to_string(&file_path)
-                .map_err(|e| format!("Failed to read welcome screen: {}", e));
-        } else {
-            eprintln!("  Not found in current directory");
-        }
-    }
-    
-    // 4. Try dev mode location (for development)
-    if let Ok(resource_path) = app.path().resource_dir() {
-        let dev_path = resource_path.parent()
-            .and_then(|p| p.parent())
-            .map(|p| p.join("dist-tstauri").join("index.md"));
-        
-        if let Some(file_path) = dev_path {
-            eprintln!("Checking dev location: {:?}", file_path);
-            if file_path.exists() {
-                eprintln!("✓ Found in dev location");
-                return fs::read_to_string(&file_path)
-                    .map_err(|e| format!("Failed to read welcome screen: {}", e));
-            } else {
-                eprintln!("  Not found in dev location");
-            }
-        }
-    }
-    
-    // Not found anywhere - provide helpful error
-    Err("Welcome screen (index.md) not found. Please ensure it's bundled in the app or in the same folder as the executable.".to_string())
 }
 
 #[tauri::command]
 fn load_bundled_shader(app: tauri::AppHandle, shader_name: String) -> Result<String, String> {
-    let shader_file = format!("{}.js", shader_name);
-    
-    // Try bundled resources first
-    if let Ok(resource_path) = app.path().resource_dir() {
-        let file_path = resource_path.join("shaders").join(&shader_file);
-        if file_path.exists() {
-            return fs::read_to_string(&file_path)
-                .map_err(|e| format!("Failed to read shader '{}': {}", shader_name, e));
-        }
-    }
-    
-    // Try current directory (portable builds)
-    let cwd_path = std::env::current_dir()
-        .ok()
-        .map(|p| p.join("shaders").join(&shader_file));
-    if let Some(file_path) = cwd_path {
-        if file_path.exists() {
-            return fs::read_to_string(&file_path)
-                .map_err(|e| format!("Failed to read shader '{}': {}", shader_name, e));
-        }
+    let key = format!("shaders/{}.js", shader_name);
+    let resolved = ResourceResolver::from_app(&app).resolve(&key).ok_or_else(|| {
+        log::error!("Shader '{}' not found in any known location", shader_name);
+        format!("Shader '{}' not found in resources or current directory", shader_name)
+    })?;
+    fs::read_to_string(&resolved.path)
+        .map_err(|e| format!("Failed to read shader '{}': {}", shader_name, e))
+}
+
+/// True when stories should be emitted into the single `main` window instead of each getting its
+/// own. Off by default; set `TSTORIE_SINGLE_WINDOW=1` to restore the original single-window flow.
+fn single_window_mode() -> bool {
+    std::env::var("TSTORIE_SINGLE_WINDOW")
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
+/// Spawns a dedicated webview window for `path`, each with its own `data_directory` under a
+/// per-story subfolder of the app data dir so stories keep isolated localStorage/state and can run
+/// concurrently. Returns the new window's label.
+fn spawn_story_window(app: &tauri::AppHandle, path: &str) -> Result<String, String> {
+    let seq = WINDOW_SEQ.fetch_add(1, Ordering::Relaxed);
+    let stem = Path::new(path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("story");
+    let sanitized: String = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let label = format!("story-{}-{}", sanitized, seq);
+
+    // Let the new window stream assets from the story's own folder via `tstorie://`.
+    if let Some(parent) = Path::new(path).parent() {
+        let scope = app.state::<ScopeState>();
+        scope.0.lock().unwrap().allow(parent.to_path_buf());
     }
-    
-    Err(format!("Shader '{}' not found in resources or current directory", shader_name))
+
+    let data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))?
+        .join("windows")
+        .join(&label);
+
+    let window = tauri::WebviewWindowBuilder::new(app, &label, tauri::WebviewUrl::default())
+        .title("tstorie")
+        .data_directory(data_dir)
+        .build()
+        .map_err(|e| format!("Failed to open story window: {}", e))?;
+
+    // Hand the file to the new window once its frontend has had a moment to attach listeners,
+    // mirroring the delayed emit used for CLI arguments.
+    let target = window.clone();
+    let file = path.to_string();
+    std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(500));
+        let _ = target.emit("file-dropped", file);
+    });
+
+    log::debug!("Opened story window '{}' for {}", label, path);
+    Ok(label)
+}
+
+/// Opens `path` in its own isolated webview window. See [`spawn_story_window`].
+#[tauri::command]
+fn open_story_window(app: tauri::AppHandle, path: String) -> Result<String, String> {
+    spawn_story_window(&app, &path)
+}
+
+/// Active filesystem watchers keyed by the path being watched, so each can be torn down
+/// individually by [`unwatch_file`]. Dropping a watcher stops it.
+struct WatcherState(Mutex<HashMap<String, RecommendedWatcher>>);
+
+/// Watches the story/shader file at `path`, emitting a `file-changed` event to the calling window
+/// whenever it's modified, so authors editing in an external editor get live updates without
+/// re-dropping the file.
+///
+/// The parent directory is watched (not just the file) so a file recreated at the same path keeps
+/// working; rapid successive writes are coalesced within ~200ms; and the payload is re-resolved
+/// through the same [`ResourceResolver`] used for the initial load.
+#[tauri::command]
+fn watch_file(
+    app: tauri::AppHandle,
+    window: tauri::Window,
+    path: String,
+    state: tauri::State<'_, WatcherState>,
+) -> Result<(), String> {
+    let target = PathBuf::from(&path);
+    // Watching the parent dir survives the delete+recreate an editor does on save.
+    let watch_dir = target
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .map(|p| p.to_path_buf())
+        .unwrap_or_else(|| target.clone());
+
+    let app_for_cb = app.clone();
+    let window_label = window.label().to_string();
+    let target_for_cb = target.clone();
+    let key = path.clone();
+    // Trailing-edge debounce: each matching event bumps the generation and arms a 200ms timer;
+    // the timer only emits if no newer event superseded it, so an in-place editor save
+    // (truncate → write → close) notifies once, after the file has settled.
+    let generation = Arc::new(AtomicUsize::new(0));
+
+    let mut watcher =
+        notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            let Ok(event) = res else { return };
+            if !event.paths.iter().any(|p| p == &target_for_cb) {
+                return;
+            }
+
+            let this_gen = generation.fetch_add(1, Ordering::SeqCst) + 1;
+            let generation = Arc::clone(&generation);
+            let app_for_cb = app_for_cb.clone();
+            let window_label = window_label.clone();
+            let key = key.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(200));
+                // A later event arrived during the wait — let its timer do the emitting.
+                if generation.load(Ordering::SeqCst) != this_gen {
+                    return;
+                }
+                // Re-resolve so a recreated file (or one moved between search locations) still loads.
+                let emit_path = ResourceResolver::from_app(&app_for_cb)
+                    .resolve(&key)
+                    .map(|r| r.path.to_string_lossy().to_string())
+                    .unwrap_or_else(|| key.clone());
+                if let Some(window) = app_for_cb.get_webview_window(&window_label) {
+                    let _ = window.emit("file-changed", emit_path);
+                }
+            });
+        })
+        .map_err(|e| format!("Failed to create watcher: {}", e))?;
+
+    watcher
+        .watch(&watch_dir, RecursiveMode::NonRecursive)
+        .map_err(|e| format!("Failed to watch '{}': {}", path, e))?;
+
+    state.0.lock().unwrap().insert(path, watcher);
+    Ok(())
+}
+
+/// Stops and removes the watcher previously started for `path` by [`watch_file`].
+#[tauri::command]
+fn unwatch_file(path: String, state: tauri::State<'_, WatcherState>) -> Result<(), String> {
+    state.0.lock().unwrap().remove(&path);
+    Ok(())
 }
 
 fn main() {
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
+        .manage(ScopeState(Mutex::new(AssetScope::default())))
+        .manage(WatcherState(Mutex::new(HashMap::new())))
+        .register_uri_scheme_protocol("tstorie", |ctx, request| {
+            serve_asset(ctx.app_handle(), request)
+        })
         .invoke_handler(tauri::generate_handler![
             load_markdown_content,
+            list_directory,
             get_bundled_wasm_path,
             get_bundled_wasm_file,
             load_bundled_welcome,
-            load_bundled_shader
+            load_bundled_shader,
+            open_story_window,
+            watch_file,
+            unwatch_file
         ])
         .setup(|app| {
             let window = app.get_webview_window("main").unwrap();
-            
+
+            // Bring up logging first so resource-resolution diagnostics below are captured.
+            init_logger(app.handle());
+
+            // Populate the `tstorie://` scope with the resource dir and exe dir only — never the
+            // CWD, which for a GUI launch is often `/` or the user's home and would widen the
+            // scope to arbitrary files. Operators grant extra locations (e.g. an external story
+            // folder) explicitly via `TSTORIE_ALLOWED_DIRS` (a platform-separated list).
+            {
+                let scope_state = app.state::<ScopeState>();
+                let mut scope = scope_state.0.lock().unwrap();
+                if let Ok(p) = app.path().resource_dir() {
+                    scope.allow(p);
+                }
+                if let Ok(exe) = std::env::current_exe() {
+                    if let Some(dir) = exe.parent() {
+                        scope.allow(dir.to_path_buf());
+                    }
+                }
+                if let Ok(extra) = std::env::var("TSTORIE_ALLOWED_DIRS") {
+                    for dir in std::env::split_paths(&extra) {
+                        scope.allow(dir);
+                    }
+                }
+            }
+
             // Check for command-line arguments (file dropped on exe)
             let args: Vec<String> = std::env::args().collect();
             if args.len() > 1 {
@@ -170,33 +710,42 @@ fn main() {
                     if path.exists() {
                         let ext = path.extension().and_then(|s| s.to_str());
                         if ext == Some("md") || ext == Some("png") {
-                            // Clone window for async emit
-                            let window_for_emit = window.clone();
-                            let file_path = arg.clone();
-                            
-                            // Emit after a short delay to ensure frontend is ready
-                            std::thread::spawn(move || {
-                                std::thread::sleep(std::time::Duration::from_millis(500));
-                                let _ = window_for_emit.emit("cli-file-arg", file_path);
-                            });
-                            
+                            if single_window_mode() {
+                                // Emit into the main window after a short delay to ensure the
+                                // frontend is ready.
+                                let window_for_emit = window.clone();
+                                let file_path = arg.clone();
+                                std::thread::spawn(move || {
+                                    std::thread::sleep(std::time::Duration::from_millis(500));
+                                    let _ = window_for_emit.emit("cli-file-arg", file_path);
+                                });
+                            } else if let Err(e) = spawn_story_window(app.handle(), arg) {
+                                log::error!("Failed to open story window for {}: {}", arg, e);
+                            }
+
                             break; // Only load first valid file
                         }
                     }
                 }
             }
             
-            // Clone window for the closure
+            // Clone handles for the closure
             let window_clone = window.clone();
-            
+            let app_handle = app.handle().clone();
+
             // Handle file drop events using window events
             window.on_window_event(move |event| {
                 if let tauri::WindowEvent::DragDrop(tauri::DragDropEvent::Drop {paths, position: _}) = event {
                     if let Some(path) = paths.first() {
                         let ext = path.extension().and_then(|s| s.to_str());
                         if ext == Some("md") || ext == Some("png") {
-                            // Send the file path to the frontend
-                            let _ = window_clone.emit("file-dropped", path.to_string_lossy().to_string());
+                            let file_path = path.to_string_lossy().to_string();
+                            if single_window_mode() {
+                                // Send the file path to the main window's frontend.
+                                let _ = window_clone.emit("file-dropped", file_path);
+                            } else if let Err(e) = spawn_story_window(&app_handle, &file_path) {
+                                log::error!("Failed to open story window for {}: {}", file_path, e);
+                            }
                         }
                     }
                 }